@@ -0,0 +1,486 @@
+use crate::types::{DataType, EnumStorage, Value};
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use sqlx::{
+    Row as _,
+    postgres::{PgPool, PgRow},
+    query::Query,
+};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum Error {
+    Sqlx(sqlx::Error),
+    NoRowsReturned,
+    /// A `Value::Array` held elements of more than one variant; Postgres arrays
+    /// require a single, uniform element type.
+    HeterogeneousArray,
+    /// A `Value::Enum` label wasn't found in its descriptor's `variants`.
+    UnknownEnumLabel(String),
+    /// `Table::update` was called with an empty set of columns to assign.
+    EmptyUpdateSet,
+    /// `Table::update` was asked to set, or `Table::select` to project or order
+    /// by, a column the `Table` doesn't declare.
+    UnknownColumn(String),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Sqlx(e) => write!(f, "{}", e),
+            Error::NoRowsReturned => write!(f, "query returned no rows"),
+            Error::HeterogeneousArray => {
+                write!(f, "Value::Array contained elements of more than one type")
+            }
+            Error::UnknownEnumLabel(label) => {
+                write!(f, "{:?} is not a valid variant for this enum column", label)
+            }
+            Error::EmptyUpdateSet => write!(f, "Table::update called with no columns to set"),
+            Error::UnknownColumn(col) => {
+                write!(f, "{:?} is not a column of this table", col)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Sqlx(e) => Some(e),
+            Error::NoRowsReturned
+            | Error::HeterogeneousArray
+            | Error::UnknownEnumLabel(_)
+            | Error::EmptyUpdateSet
+            | Error::UnknownColumn(_) => None,
+        }
+    }
+}
+
+impl From<sqlx::Error> for Error {
+    fn from(e: sqlx::Error) -> Self {
+        Error::Sqlx(e)
+    }
+}
+
+/// A decoded row, opaque to the driver that produced it.
+///
+/// `Table` only ever asks a `Row` for a `Value` by column name and expected
+/// `DataType`, so the SQL-building code in `Table`/`Condition` stays driver-agnostic.
+pub struct Row(RowInner);
+
+enum RowInner {
+    Sqlx(PgRow),
+}
+
+impl Row {
+    pub fn get_value(&self, name: &str, data_type: &DataType) -> Result<Value, Error> {
+        match &self.0 {
+            RowInner::Sqlx(row) => get_value(row, name, data_type).map_err(Error::from),
+        }
+    }
+}
+
+/// Backend-agnostic entry point used by `Table` to run the SQL it builds.
+///
+/// Implement this to plug in a different driver (e.g. `tokio-postgres`) while
+/// reusing the query-building logic in `Table` and `Condition` unchanged.
+#[async_trait]
+pub trait Client: Send + Sync {
+    async fn execute(&self, sql: &str, params: Vec<Value>) -> Result<u64, Error>;
+    async fn query(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, Error>;
+}
+
+/// `Client` impl backed by `sqlx`'s `PgPool`.
+pub struct SqlxClient {
+    pool: PgPool,
+}
+
+impl SqlxClient {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl Client for SqlxClient {
+    async fn execute(&self, sql: &str, params: Vec<Value>) -> Result<u64, Error> {
+        let mut query = sqlx::query(sql);
+        for p in &params {
+            query = bind(query, p)?;
+        }
+        let result = query.execute(&self.pool).await?;
+        Ok(result.rows_affected())
+    }
+
+    async fn query(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, Error> {
+        let mut query = sqlx::query(sql);
+        for p in &params {
+            query = bind(query, p)?;
+        }
+        let rows = query.fetch_all(&self.pool).await?;
+        Ok(rows.into_iter().map(|r| Row(RowInner::Sqlx(r))).collect())
+    }
+}
+
+fn bind<'q>(
+    q: Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>,
+    v: &Value,
+) -> Result<Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>, Error> {
+    let q = match v {
+        Value::Int(i) => q.bind(*i),
+        Value::Text(s) => q.bind(s.clone()),
+        Value::Bool(b) => q.bind(*b),
+        Value::Timestamp(t) => q.bind(*t),
+        Value::Timestamptz(t) => q.bind(*t),
+        Value::Date(d) => q.bind(*d),
+        Value::Time(t) => q.bind(*t),
+        Value::Numeric(n) => q.bind(n.clone()),
+        Value::Uuid(u) => q.bind(*u),
+        Value::Json(j) => q.bind(j.clone()),
+        Value::Array(items) => return bind_array(q, items),
+        Value::Enum(label, descriptor) => match &descriptor.storage {
+            EnumStorage::Weak => {
+                let discriminant = descriptor
+                    .variants
+                    .iter()
+                    .position(|v| v == label)
+                    .ok_or_else(|| Error::UnknownEnumLabel(label.clone()))?
+                    as i32;
+                q.bind(discriminant)
+            }
+            // Strong and native enums both bind as their text label; a genuine
+            // native `CREATE TYPE ... AS ENUM` type has no compile-time
+            // `sqlx::Type` impl to bind against a dynamic label. The SQL text
+            // built by `Table`/`Condition` casts the placeholder to the enum's
+            // type name via `Value::placeholder`, so Postgres coerces this TEXT
+            // parameter on arrival instead of needing an OID match up front.
+            EnumStorage::Strong | EnumStorage::Native(_) => q.bind(label.clone()),
+        },
+        Value::Null => q.bind::<Option<i32>>(None),
+    };
+    Ok(q)
+}
+
+fn bind_array<'q>(
+    q: Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>,
+    items: &[Value],
+) -> Result<Query<'q, sqlx::Postgres, sqlx::postgres::PgArguments>, Error> {
+    let q = match items.first() {
+        Some(Value::Int(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Int(i) => Ok(*i),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<i64>, Error>>()?,
+        ),
+        Some(Value::Text(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Text(s) => Ok(s.clone()),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<String>, Error>>()?,
+        ),
+        Some(Value::Bool(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Bool(b) => Ok(*b),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<bool>, Error>>()?,
+        ),
+        Some(Value::Timestamp(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Timestamp(t) => Ok(*t),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<NaiveDateTime>, Error>>()?,
+        ),
+        Some(Value::Timestamptz(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Timestamptz(t) => Ok(*t),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<DateTime<Utc>>, Error>>()?,
+        ),
+        Some(Value::Date(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Date(d) => Ok(*d),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<NaiveDate>, Error>>()?,
+        ),
+        Some(Value::Time(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Time(t) => Ok(*t),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<NaiveTime>, Error>>()?,
+        ),
+        Some(Value::Numeric(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Numeric(n) => Ok(n.clone()),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<BigDecimal>, Error>>()?,
+        ),
+        Some(Value::Uuid(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Uuid(u) => Ok(*u),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<Uuid>, Error>>()?,
+        ),
+        Some(Value::Enum(_, descriptor)) => match &descriptor.storage {
+            EnumStorage::Weak => q.bind(
+                items
+                    .iter()
+                    .map(|v| match v {
+                        Value::Enum(label, d) => d
+                            .variants
+                            .iter()
+                            .position(|v| v == label)
+                            .map(|i| i as i32)
+                            .ok_or_else(|| Error::UnknownEnumLabel(label.clone())),
+                        _ => Err(Error::HeterogeneousArray),
+                    })
+                    .collect::<Result<Vec<i32>, Error>>()?,
+            ),
+            EnumStorage::Strong | EnumStorage::Native(_) => q.bind(
+                items
+                    .iter()
+                    .map(|v| match v {
+                        Value::Enum(label, _) => Ok(label.clone()),
+                        _ => Err(Error::HeterogeneousArray),
+                    })
+                    .collect::<Result<Vec<String>, Error>>()?,
+            ),
+        },
+        Some(Value::Json(_)) => q.bind(
+            items
+                .iter()
+                .map(|v| match v {
+                    Value::Json(j) => Ok(j.clone()),
+                    _ => Err(Error::HeterogeneousArray),
+                })
+                .collect::<Result<Vec<serde_json::Value>, Error>>()?,
+        ),
+        Some(Value::Null) if items.iter().any(|v| !matches!(v, Value::Null)) => {
+            return Err(Error::HeterogeneousArray);
+        }
+        // Empty or all-NULL arrays have no element to infer a wire type from; TEXT[] is
+        // the safest default since Postgres will happily coerce an empty array literal.
+        _ => q.bind(Vec::<String>::new()),
+    };
+    Ok(q)
+}
+
+fn get_value(row: &PgRow, name: &str, data_type: &DataType) -> Result<Value, sqlx::Error> {
+    let val = match data_type {
+        DataType::Int => {
+            let v: Option<i64> = row.try_get(name)?;
+            v.map(Value::Int).unwrap_or(Value::Null)
+        }
+        DataType::Text => {
+            let v: Option<String> = row.try_get(name)?;
+            v.map(Value::Text).unwrap_or(Value::Null)
+        }
+        DataType::Bool => {
+            let v: Option<bool> = row.try_get(name)?;
+            v.map(Value::Bool).unwrap_or(Value::Null)
+        }
+        DataType::Timestamp => {
+            let v: Option<NaiveDateTime> = row.try_get(name)?;
+            v.map(Value::Timestamp).unwrap_or(Value::Null)
+        }
+        DataType::Timestamptz => {
+            let v: Option<DateTime<Utc>> = row.try_get(name)?;
+            v.map(Value::Timestamptz).unwrap_or(Value::Null)
+        }
+        DataType::Date => {
+            let v: Option<NaiveDate> = row.try_get(name)?;
+            v.map(Value::Date).unwrap_or(Value::Null)
+        }
+        DataType::Time => {
+            let v: Option<NaiveTime> = row.try_get(name)?;
+            v.map(Value::Time).unwrap_or(Value::Null)
+        }
+        DataType::Numeric => {
+            let v: Option<BigDecimal> = row.try_get(name)?;
+            v.map(Value::Numeric).unwrap_or(Value::Null)
+        }
+        DataType::Uuid => {
+            let v: Option<Uuid> = row.try_get(name)?;
+            v.map(Value::Uuid).unwrap_or(Value::Null)
+        }
+        DataType::Json => {
+            let v: Option<serde_json::Value> = row.try_get(name)?;
+            v.map(Value::Json).unwrap_or(Value::Null)
+        }
+        DataType::Array(inner) => get_array_value(row, name, inner)?,
+        DataType::Unsupported(_) => {
+            // Best-effort: decode as text, and treat a wire-level mismatch as NULL
+            // rather than failing the whole row.
+            row.try_get::<Option<String>, _>(name)
+                .ok()
+                .flatten()
+                .map(Value::Text)
+                .unwrap_or(Value::Null)
+        }
+        DataType::Enum(descriptor) => match &descriptor.storage {
+            EnumStorage::Weak => {
+                let v: Option<i32> = row.try_get(name)?;
+                v.and_then(|i| descriptor.variants.get(i as usize).cloned())
+                    .map(|label| Value::Enum(label, descriptor.clone()))
+                    .unwrap_or(Value::Null)
+            }
+            EnumStorage::Strong | EnumStorage::Native(_) => {
+                let v: Option<String> = row.try_get(name)?;
+                v.map(|label| Value::Enum(label, descriptor.clone()))
+                    .unwrap_or(Value::Null)
+            }
+        },
+    };
+    Ok(val)
+}
+
+fn get_array_value(row: &PgRow, name: &str, inner: &DataType) -> Result<Value, sqlx::Error> {
+    let val = match inner {
+        DataType::Int => {
+            let v: Option<Vec<i64>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Int).collect()))
+        }
+        DataType::Text => {
+            let v: Option<Vec<String>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Text).collect()))
+        }
+        DataType::Bool => {
+            let v: Option<Vec<bool>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Bool).collect()))
+        }
+        DataType::Timestamp => {
+            let v: Option<Vec<NaiveDateTime>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Timestamp).collect()))
+        }
+        DataType::Timestamptz => {
+            let v: Option<Vec<DateTime<Utc>>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Timestamptz).collect()))
+        }
+        DataType::Date => {
+            let v: Option<Vec<NaiveDate>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Date).collect()))
+        }
+        DataType::Time => {
+            let v: Option<Vec<NaiveTime>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Time).collect()))
+        }
+        DataType::Numeric => {
+            let v: Option<Vec<BigDecimal>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Numeric).collect()))
+        }
+        DataType::Uuid => {
+            let v: Option<Vec<Uuid>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Uuid).collect()))
+        }
+        DataType::Json => {
+            let v: Option<Vec<serde_json::Value>> = row.try_get(name)?;
+            v.map(|items| Value::Array(items.into_iter().map(Value::Json).collect()))
+        }
+        DataType::Array(_) => {
+            // Postgres doesn't support nested arrays as a distinct wire type from
+            // sqlx here; treat unsupported nesting as an absent value rather than panic.
+            None
+        }
+        DataType::Unsupported(_) => row
+            .try_get::<Option<Vec<String>>, _>(name)
+            .ok()
+            .flatten()
+            .map(|items| Value::Array(items.into_iter().map(Value::Text).collect())),
+        DataType::Enum(descriptor) => match &descriptor.storage {
+            EnumStorage::Weak => {
+                let v: Option<Vec<i32>> = row.try_get(name)?;
+                v.map(|items| {
+                    Value::Array(
+                        items
+                            .into_iter()
+                            .filter_map(|i| descriptor.variants.get(i as usize).cloned())
+                            .map(|label| Value::Enum(label, descriptor.clone()))
+                            .collect(),
+                    )
+                })
+            }
+            EnumStorage::Strong | EnumStorage::Native(_) => {
+                let v: Option<Vec<String>> = row.try_get(name)?;
+                v.map(|items| {
+                    Value::Array(
+                        items
+                            .into_iter()
+                            .map(|label| Value::Enum(label, descriptor.clone()))
+                            .collect(),
+                    )
+                })
+            }
+        },
+    };
+    Ok(val.unwrap_or(Value::Null))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EnumDescriptor;
+
+    fn color_descriptor() -> EnumDescriptor {
+        EnumDescriptor {
+            variants: vec!["red".to_string(), "green".to_string(), "blue".to_string()],
+            storage: EnumStorage::Weak,
+        }
+    }
+
+    #[test]
+    fn bind_array_rejects_heterogeneous_elements() {
+        let items = vec![Value::Int(1), Value::Text("oops".to_string())];
+        let err = bind_array(sqlx::query("SELECT 1"), &items).err().unwrap();
+        assert!(matches!(err, Error::HeterogeneousArray));
+    }
+
+    #[test]
+    fn bind_rejects_unknown_weak_enum_label() {
+        let value = Value::Enum("purple".to_string(), color_descriptor());
+        let err = bind(sqlx::query("SELECT 1"), &value).err().unwrap();
+        assert!(matches!(err, Error::UnknownEnumLabel(label) if label == "purple"));
+    }
+
+    #[test]
+    fn bind_accepts_known_weak_enum_label() {
+        let value = Value::Enum("green".to_string(), color_descriptor());
+        assert!(bind(sqlx::query("SELECT 1"), &value).is_ok());
+    }
+
+    #[test]
+    fn bind_array_rejects_unknown_weak_enum_label() {
+        let items = vec![
+            Value::Enum("green".to_string(), color_descriptor()),
+            Value::Enum("purple".to_string(), color_descriptor()),
+        ];
+        let err = bind_array(sqlx::query("SELECT 1"), &items).err().unwrap();
+        assert!(matches!(err, Error::UnknownEnumLabel(label) if label == "purple"));
+    }
+}
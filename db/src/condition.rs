@@ -0,0 +1,187 @@
+use crate::types::Value;
+
+#[derive(Clone, Debug)]
+pub enum Condition {
+    Eq(String, Value),
+    Ne(String, Value),
+    Lt(String, Value),
+    Le(String, Value),
+    Gt(String, Value),
+    Ge(String, Value),
+    In(String, Vec<Value>),
+    Like(String, String),
+    Between(String, Value, Value),
+    Not(Box<Condition>),
+    And(Box<Condition>, Box<Condition>),
+    Or(Box<Condition>, Box<Condition>),
+}
+
+impl Condition {
+    pub(crate) fn build(&self, args: &mut Vec<Value>, idx: &mut i32) -> String {
+        match self {
+            Condition::Eq(col, val) => match val {
+                Value::Null => format!("{} IS NULL", col),
+                _ => {
+                    *idx += 1;
+                    let placeholder = val.placeholder(*idx);
+                    args.push(val.clone());
+                    format!("{} = {}", col, placeholder)
+                }
+            },
+            Condition::Ne(col, val) => match val {
+                Value::Null => format!("{} IS NOT NULL", col),
+                _ => {
+                    *idx += 1;
+                    let placeholder = val.placeholder(*idx);
+                    args.push(val.clone());
+                    format!("{} != {}", col, placeholder)
+                }
+            },
+            Condition::Lt(col, val) => Self::build_cmp(col, val, "<", args, idx),
+            Condition::Le(col, val) => Self::build_cmp(col, val, "<=", args, idx),
+            Condition::Gt(col, val) => Self::build_cmp(col, val, ">", args, idx),
+            Condition::Ge(col, val) => Self::build_cmp(col, val, ">=", args, idx),
+            Condition::In(col, vals) => {
+                if vals.is_empty() {
+                    // `col IN ()` is a Postgres syntax error; an empty list matches nothing.
+                    return "FALSE".to_string();
+                }
+                let placeholders: Vec<String> = vals
+                    .iter()
+                    .map(|v| {
+                        *idx += 1;
+                        let placeholder = v.placeholder(*idx);
+                        args.push(v.clone());
+                        placeholder
+                    })
+                    .collect();
+                format!("{} IN ({})", col, placeholders.join(", "))
+            }
+            Condition::Like(col, pattern) => {
+                *idx += 1;
+                args.push(Value::Text(pattern.clone()));
+                format!("{} LIKE ${}", col, idx)
+            }
+            Condition::Between(col, lo, hi) => {
+                *idx += 1;
+                let lo_placeholder = lo.placeholder(*idx);
+                args.push(lo.clone());
+                *idx += 1;
+                let hi_placeholder = hi.placeholder(*idx);
+                args.push(hi.clone());
+                format!("{} BETWEEN {} AND {}", col, lo_placeholder, hi_placeholder)
+            }
+            Condition::Not(inner) => {
+                let sql = inner.build(args, idx);
+                format!("NOT ({})", sql)
+            }
+            Condition::And(l, r) => {
+                let lsql = l.build(args, idx);
+                let rsql = r.build(args, idx);
+                format!("({}) AND ({})", lsql, rsql)
+            }
+            Condition::Or(l, r) => {
+                let lsql = l.build(args, idx);
+                let rsql = r.build(args, idx);
+                format!("({}) OR ({})", lsql, rsql)
+            }
+        }
+    }
+
+    fn build_cmp(col: &str, val: &Value, op: &str, args: &mut Vec<Value>, idx: &mut i32) -> String {
+        *idx += 1;
+        let placeholder = val.placeholder(*idx);
+        args.push(val.clone());
+        format!("{} {} {}", col, op, placeholder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EnumDescriptor, EnumStorage};
+
+    #[test]
+    fn eq_with_native_enum_casts_the_placeholder_to_its_type_name() {
+        let descriptor = EnumDescriptor {
+            variants: vec!["red".to_string(), "green".to_string()],
+            storage: EnumStorage::Native("color".to_string()),
+        };
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::Eq(
+            "color".to_string(),
+            Value::Enum("green".to_string(), descriptor),
+        )
+        .build(&mut args, &mut idx);
+        assert_eq!(sql, "color = $1::color");
+    }
+
+    #[test]
+    fn eq_null_builds_is_null_without_a_placeholder() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::Eq("name".to_string(), Value::Null).build(&mut args, &mut idx);
+        assert_eq!(sql, "name IS NULL");
+        assert!(args.is_empty());
+    }
+
+    #[test]
+    fn ne_non_null_builds_a_placeholder() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql =
+            Condition::Ne("name".to_string(), Value::Text("a".into())).build(&mut args, &mut idx);
+        assert_eq!(sql, "name != $1");
+        assert_eq!(args, vec![Value::Text("a".into())]);
+    }
+
+    #[test]
+    fn in_with_values_builds_one_placeholder_per_value() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::In("id".to_string(), vec![Value::Int(1), Value::Int(2)])
+            .build(&mut args, &mut idx);
+        assert_eq!(sql, "id IN ($1, $2)");
+        assert_eq!(args, vec![Value::Int(1), Value::Int(2)]);
+    }
+
+    #[test]
+    fn in_with_no_values_builds_false_and_binds_nothing() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::In("id".to_string(), vec![]).build(&mut args, &mut idx);
+        assert_eq!(sql, "FALSE");
+        assert!(args.is_empty());
+        assert_eq!(idx, 0);
+    }
+
+    #[test]
+    fn between_uses_two_consecutive_placeholders() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::Between("id".to_string(), Value::Int(1), Value::Int(10))
+            .build(&mut args, &mut idx);
+        assert_eq!(sql, "id BETWEEN $1 AND $2");
+        assert_eq!(args, vec![Value::Int(1), Value::Int(10)]);
+    }
+
+    #[test]
+    fn and_or_not_compose_and_share_one_placeholder_counter() {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let sql = Condition::And(
+            Box::new(Condition::Not(Box::new(Condition::Eq(
+                "a".to_string(),
+                Value::Int(1),
+            )))),
+            Box::new(Condition::Or(
+                Box::new(Condition::Eq("b".to_string(), Value::Int(2))),
+                Box::new(Condition::Eq("c".to_string(), Value::Int(3))),
+            )),
+        )
+        .build(&mut args, &mut idx);
+        assert_eq!(sql, "(NOT (a = $1)) AND ((b = $2) OR (c = $3))");
+        assert_eq!(args, vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+    }
+}
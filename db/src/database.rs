@@ -0,0 +1,60 @@
+use crate::client::{Client, Error};
+use crate::types::{DataType, Value};
+
+/// Database-wide (as opposed to single-table) introspection helpers.
+pub struct Database;
+
+impl Database {
+    /// Lists the tables in `schema`, for discovering what to pass to
+    /// `Table::introspect`.
+    pub async fn tables<C: Client>(client: &C, schema: &str) -> Result<Vec<String>, Error> {
+        let sql = "SELECT table_name FROM information_schema.tables \
+                    WHERE table_schema = $1 ORDER BY table_name";
+        let rows = client.query(sql, vec![Value::Text(schema.to_string())]).await?;
+        let mut names = Vec::new();
+        for row in &rows {
+            if let Value::Text(name) = row.get_value("table_name", &DataType::Text)? {
+                names.push(name);
+            }
+        }
+        Ok(names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Row;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// A `Client` stub that records the SQL/params it's given instead of
+    /// talking to Postgres, so the SQL this module builds can be unit-tested
+    /// without a live database.
+    #[derive(Default)]
+    struct RecordingClient {
+        queries: Mutex<Vec<(String, Vec<Value>)>>,
+    }
+
+    #[async_trait]
+    impl Client for RecordingClient {
+        async fn execute(&self, sql: &str, params: Vec<Value>) -> Result<u64, Error> {
+            self.queries.lock().unwrap().push((sql.to_string(), params));
+            Ok(0)
+        }
+
+        async fn query(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, Error> {
+            self.queries.lock().unwrap().push((sql.to_string(), params));
+            Ok(Vec::new())
+        }
+    }
+
+    #[tokio::test]
+    async fn tables_filters_by_the_given_schema() {
+        let client = RecordingClient::default();
+        Database::tables(&client, "public").await.unwrap();
+        let queries = client.queries.lock().unwrap();
+        assert!(queries[0].0.contains("table_schema = $1"));
+        assert_eq!(queries[0].1, vec![Value::Text("public".to_string())]);
+    }
+}
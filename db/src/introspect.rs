@@ -0,0 +1,80 @@
+use crate::types::DataType;
+
+/// Maps an `information_schema.columns` (`data_type`, `udt_name`) pair onto our
+/// `DataType`. Falls back to `DataType::Unsupported` rather than failing so that
+/// introspecting a table with an exotic column type never errors outright.
+pub(crate) fn map_pg_type(data_type: &str, udt_name: &str) -> DataType {
+    if data_type == "ARRAY" {
+        return DataType::Array(Box::new(map_udt_name(udt_name.trim_start_matches('_'))));
+    }
+    match data_type {
+        "smallint" | "integer" | "bigint" => DataType::Int,
+        "text" | "character varying" | "character" => DataType::Text,
+        "boolean" => DataType::Bool,
+        "timestamp without time zone" => DataType::Timestamp,
+        "timestamp with time zone" => DataType::Timestamptz,
+        "date" => DataType::Date,
+        "time without time zone" | "time with time zone" => DataType::Time,
+        "numeric" | "decimal" => DataType::Numeric,
+        "uuid" => DataType::Uuid,
+        "json" | "jsonb" => DataType::Json,
+        _ => DataType::Unsupported(udt_name.to_string()),
+    }
+}
+
+fn map_udt_name(udt_name: &str) -> DataType {
+    match udt_name {
+        "int2" | "int4" | "int8" => DataType::Int,
+        "text" | "varchar" | "bpchar" => DataType::Text,
+        "bool" => DataType::Bool,
+        "timestamp" => DataType::Timestamp,
+        "timestamptz" => DataType::Timestamptz,
+        "date" => DataType::Date,
+        "time" | "timetz" => DataType::Time,
+        "numeric" => DataType::Numeric,
+        "uuid" => DataType::Uuid,
+        "json" | "jsonb" => DataType::Json,
+        other => DataType::Unsupported(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_pg_type_distinguishes_timestamp_with_and_without_time_zone() {
+        assert!(matches!(
+            map_pg_type("timestamp without time zone", "timestamp"),
+            DataType::Timestamp
+        ));
+        assert!(matches!(
+            map_pg_type("timestamp with time zone", "timestamptz"),
+            DataType::Timestamptz
+        ));
+    }
+
+    #[test]
+    fn map_pg_type_maps_common_scalar_types() {
+        assert!(matches!(map_pg_type("integer", "int4"), DataType::Int));
+        assert!(matches!(map_pg_type("boolean", "bool"), DataType::Bool));
+        assert!(matches!(map_pg_type("uuid", "uuid"), DataType::Uuid));
+        assert!(matches!(map_pg_type("jsonb", "jsonb"), DataType::Json));
+    }
+
+    #[test]
+    fn map_pg_type_falls_back_to_unsupported_for_unknown_types() {
+        match map_pg_type("point", "point") {
+            DataType::Unsupported(udt) => assert_eq!(udt, "point"),
+            other => panic!("expected Unsupported, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn map_pg_type_maps_arrays_by_their_stripped_element_udt_name() {
+        match map_pg_type("ARRAY", "_int4") {
+            DataType::Array(inner) => assert!(matches!(*inner, DataType::Int)),
+            other => panic!("expected Array, got {:?}", other),
+        }
+    }
+}
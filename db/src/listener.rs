@@ -0,0 +1,242 @@
+use crate::table::Table;
+use crate::types::{Column, DataType, EnumStorage, Value};
+use sqlx::postgres::{PgListener, PgPool};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, broadcast};
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+pub(crate) fn change_channel(table_name: &str) -> String {
+    format!("db_change_{}", table_name)
+}
+
+/// A decoded row-change event published by the trigger installed via
+/// `Table::install_change_trigger`.
+#[derive(Clone, Debug)]
+pub struct Notification {
+    pub table: String,
+    pub operation: String,
+    pub row: HashMap<String, Value>,
+}
+
+struct Channel {
+    sender: broadcast::Sender<Notification>,
+    table_name: String,
+    columns: Vec<Column>,
+}
+
+/// Subscribes to `LISTEN/NOTIFY` row-change events for one or more tables.
+///
+/// A single background task per channel relays Postgres notifications to every
+/// subscriber registered through `subscribe`, so calling `subscribe` for the
+/// same table twice reuses one Postgres connection instead of opening two.
+pub struct Listener {
+    pool: PgPool,
+    channels: Arc<Mutex<HashMap<String, Channel>>>,
+}
+
+impl Listener {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            channels: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Subscribe to insert/update/delete notifications for `table`. The table
+    /// must already have a change trigger installed (see
+    /// `Table::install_change_trigger`).
+    pub async fn subscribe(&self, table: &Table) -> impl Stream<Item = Notification> {
+        let channel_name = change_channel(&table.name);
+        let mut channels = self.channels.lock().await;
+        let sender = match channels.get(&channel_name) {
+            Some(channel) => channel.sender.clone(),
+            None => {
+                let (sender, _) = broadcast::channel(256);
+                channels.insert(
+                    channel_name.clone(),
+                    Channel {
+                        sender: sender.clone(),
+                        table_name: table.name.clone(),
+                        columns: table.columns.clone(),
+                    },
+                );
+                let pool = self.pool.clone();
+                let channels = Arc::clone(&self.channels);
+                tokio::spawn(run_channel(pool, channel_name, channels));
+                sender
+            }
+        };
+        let receiver = sender.subscribe();
+        BroadcastStream::new(receiver).filter_map(|n| n.ok())
+    }
+}
+
+/// Owns the dedicated Postgres connection for one channel, reconnecting
+/// whenever it drops, and fans decoded payloads out to every subscriber.
+async fn run_channel(pool: PgPool, channel_name: String, channels: Arc<Mutex<HashMap<String, Channel>>>) {
+    loop {
+        let mut listener = match PgListener::connect_with(&pool).await {
+            Ok(l) => l,
+            Err(_) => {
+                tokio::time::sleep(RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+        if listener.listen(&channel_name).await.is_err() {
+            tokio::time::sleep(RECONNECT_DELAY).await;
+            continue;
+        }
+        loop {
+            let notification = match listener.recv().await {
+                Ok(n) => n,
+                Err(_) => break, // connection dropped; reconnect in the outer loop
+            };
+            let guard = channels.lock().await;
+            let Some(channel) = guard.get(&channel_name) else {
+                return; // no subscribers left for this channel
+            };
+            if channel.sender.receiver_count() == 0 {
+                continue;
+            }
+            let payload: serde_json::Value = match serde_json::from_str(notification.payload()) {
+                Ok(v) => v,
+                Err(_) => continue,
+            };
+            let operation = payload
+                .get("op")
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string();
+            let row = decode_row(payload.get("row"), &channel.columns);
+            let _ = channel.sender.send(Notification {
+                table: channel.table_name.clone(),
+                operation,
+                row,
+            });
+        }
+    }
+}
+
+fn decode_row(row: Option<&serde_json::Value>, columns: &[Column]) -> HashMap<String, Value> {
+    let mut map = HashMap::new();
+    for col in columns {
+        let value = row
+            .and_then(|r| r.get(&col.name))
+            .map(|v| json_to_value(v, &col.data_type))
+            .unwrap_or(Value::Null);
+        map.insert(col.name.clone(), value);
+    }
+    map
+}
+
+fn json_to_value(v: &serde_json::Value, data_type: &DataType) -> Value {
+    if v.is_null() {
+        return Value::Null;
+    }
+    match data_type {
+        DataType::Int => v.as_i64().map(Value::Int).unwrap_or(Value::Null),
+        DataType::Text => v.as_str().map(|s| Value::Text(s.to_string())).unwrap_or(Value::Null),
+        DataType::Bool => v.as_bool().map(Value::Bool).unwrap_or(Value::Null),
+        DataType::Timestamp => v
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Timestamp)
+            .unwrap_or(Value::Null),
+        DataType::Timestamptz => v
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Timestamptz)
+            .unwrap_or(Value::Null),
+        DataType::Date => v
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Date)
+            .unwrap_or(Value::Null),
+        DataType::Time => v
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Time)
+            .unwrap_or(Value::Null),
+        DataType::Numeric => {
+            // row_to_json (used by the trigger installed via
+            // `Table::install_change_trigger`) emits NUMERIC columns as bare
+            // JSON numbers, not strings, unlike the temporal/UUID types above.
+            let text = match v {
+                serde_json::Value::Number(n) => Some(n.to_string()),
+                serde_json::Value::String(s) => Some(s.clone()),
+                _ => None,
+            };
+            text.and_then(|s| s.parse().ok())
+                .map(Value::Numeric)
+                .unwrap_or(Value::Null)
+        }
+        DataType::Uuid => v
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .map(Value::Uuid)
+            .unwrap_or(Value::Null),
+        DataType::Json => Value::Json(v.clone()),
+        DataType::Array(inner) => v
+            .as_array()
+            .map(|items| Value::Array(items.iter().map(|i| json_to_value(i, inner)).collect()))
+            .unwrap_or(Value::Null),
+        DataType::Unsupported(_) => v.as_str().map(|s| Value::Text(s.to_string())).unwrap_or(Value::Null),
+        DataType::Enum(descriptor) => match &descriptor.storage {
+            EnumStorage::Weak => v
+                .as_i64()
+                .and_then(|i| descriptor.variants.get(i as usize).cloned())
+                .map(|label| Value::Enum(label, descriptor.clone()))
+                .unwrap_or(Value::Null),
+            EnumStorage::Strong | EnumStorage::Native(_) => v
+                .as_str()
+                .map(|s| Value::Enum(s.to_string(), descriptor.clone()))
+                .unwrap_or(Value::Null),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Column, EnumDescriptor};
+
+    #[test]
+    fn decode_row_reads_numeric_from_bare_json_number() {
+        let table = Table::new("orders", vec![Column::new("amount", DataType::Numeric)]);
+        // The trigger installed via `install_change_trigger` encodes rows with
+        // `row_to_json`, which is exactly why NUMERIC arrives as a bare number.
+        assert!(table.change_trigger_ddl().contains("row_to_json"));
+
+        let payload = serde_json::json!({ "amount": 12.50 });
+        let row = decode_row(Some(&payload), &table.columns);
+
+        assert_eq!(
+            row.get("amount"),
+            Some(&Value::Numeric("12.5".parse().unwrap()))
+        );
+    }
+
+    #[test]
+    fn json_to_value_decodes_weak_enum_from_discriminant() {
+        let descriptor = EnumDescriptor {
+            variants: vec!["red".to_string(), "green".to_string()],
+            storage: EnumStorage::Weak,
+        };
+        let value = json_to_value(&serde_json::json!(1), &DataType::Enum(descriptor.clone()));
+        assert_eq!(value, Value::Enum("green".to_string(), descriptor));
+    }
+
+    #[test]
+    fn json_to_value_decodes_strong_enum_from_text() {
+        let descriptor = EnumDescriptor {
+            variants: vec!["red".to_string(), "green".to_string()],
+            storage: EnumStorage::Strong,
+        };
+        let value = json_to_value(&serde_json::json!("green"), &DataType::Enum(descriptor.clone()));
+        assert_eq!(value, Value::Enum("green".to_string(), descriptor));
+    }
+}
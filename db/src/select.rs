@@ -0,0 +1,23 @@
+#[derive(Clone, Debug)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+impl Order {
+    pub(crate) fn as_sql(&self) -> &'static str {
+        match self {
+            Order::Asc => "ASC",
+            Order::Desc => "DESC",
+        }
+    }
+}
+
+/// Query options for `Table::select`: projection, ordering, and paging.
+#[derive(Clone, Debug, Default)]
+pub struct SelectOptions {
+    pub order_by: Vec<(String, Order)>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub columns: Option<Vec<String>>,
+}
@@ -0,0 +1,571 @@
+use crate::client::{Client, Error, Row};
+use crate::condition::Condition;
+use crate::introspect::map_pg_type;
+use crate::listener::change_channel;
+use crate::select::SelectOptions;
+use crate::types::{Column, DataType, Value};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Clone, Debug)]
+pub struct Table {
+    pub name: String,
+    pub columns: Vec<Column>,
+}
+
+impl Table {
+    pub fn new(name: &str, columns: Vec<Column>) -> Self {
+        Self {
+            name: name.into(),
+            columns,
+        }
+    }
+
+    /// Builds a `Table` from an existing database table in `schema` by querying
+    /// `information_schema`, so callers don't have to hand-declare every column.
+    ///
+    /// `schema` must be filtered on explicitly (see `Database::tables`):
+    /// without it, a table name that exists in more than one schema would
+    /// have its columns and primary keys drawn from whichever schema's rows
+    /// `information_schema` happens to return first.
+    pub async fn introspect<C: Client>(
+        client: &C,
+        schema: &str,
+        table_name: &str,
+    ) -> Result<Table, Error> {
+        let pk_sql = "SELECT kcu.column_name FROM information_schema.table_constraints tc \
+                      JOIN information_schema.key_column_usage kcu \
+                        ON tc.constraint_name = kcu.constraint_name \
+                       AND tc.table_schema = kcu.table_schema \
+                      WHERE tc.table_schema = $1 AND tc.table_name = $2 \
+                        AND tc.constraint_type = 'PRIMARY KEY'";
+        let pk_rows = client
+            .query(
+                pk_sql,
+                vec![
+                    Value::Text(schema.to_string()),
+                    Value::Text(table_name.to_string()),
+                ],
+            )
+            .await?;
+        let mut primary_keys = HashSet::new();
+        for row in &pk_rows {
+            if let Value::Text(name) = row.get_value("column_name", &DataType::Text)? {
+                primary_keys.insert(name);
+            }
+        }
+
+        let columns_sql = "SELECT column_name, data_type, udt_name, is_nullable \
+                            FROM information_schema.columns \
+                            WHERE table_schema = $1 AND table_name = $2 \
+                            ORDER BY ordinal_position";
+        let rows = client
+            .query(
+                columns_sql,
+                vec![
+                    Value::Text(schema.to_string()),
+                    Value::Text(table_name.to_string()),
+                ],
+            )
+            .await?;
+        let mut columns = Vec::new();
+        for row in &rows {
+            let name = match row.get_value("column_name", &DataType::Text)? {
+                Value::Text(s) => s,
+                _ => continue,
+            };
+            let data_type_str = match row.get_value("data_type", &DataType::Text)? {
+                Value::Text(s) => s,
+                _ => String::new(),
+            };
+            let udt_name = match row.get_value("udt_name", &DataType::Text)? {
+                Value::Text(s) => s,
+                _ => String::new(),
+            };
+            let nullable = matches!(
+                row.get_value("is_nullable", &DataType::Text)?,
+                Value::Text(s) if s == "YES"
+            );
+            columns.push(Column {
+                primary_key: primary_keys.contains(&name),
+                nullable,
+                data_type: map_pg_type(&data_type_str, &udt_name),
+                name,
+            });
+        }
+
+        Ok(Table::new(table_name, columns))
+    }
+
+    pub async fn insert<C: Client>(
+        &self,
+        client: &C,
+        mut values: HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>, Error> {
+        let mut cols = Vec::new();
+        let mut placeholders = Vec::new();
+        let mut binds = Vec::new();
+        let mut idx = 1;
+        for c in &self.columns {
+            if let Some(v) = values.remove(&c.name) {
+                cols.push(c.name.clone());
+                match v {
+                    Value::Null => placeholders.push("NULL".to_string()),
+                    _ => {
+                        placeholders.push(v.placeholder(idx));
+                        idx += 1;
+                        binds.push(v);
+                    }
+                }
+            }
+        }
+        let sql = format!(
+            "INSERT INTO {} ({}) VALUES ({}) RETURNING *",
+            self.name,
+            cols.join(", "),
+            placeholders.join(", ")
+        );
+        let rows = client.query(&sql, binds).await?;
+        let row = rows.into_iter().next().ok_or(Error::NoRowsReturned)?;
+        row_to_map(&row, &self.columns)
+    }
+
+    pub async fn select<C: Client>(
+        &self,
+        client: &C,
+        condition: Option<Condition>,
+        options: Option<SelectOptions>,
+    ) -> Result<Vec<HashMap<String, Value>>, Error> {
+        let options = options.unwrap_or_default();
+        // Project only columns this Table actually knows about, and derive the
+        // SQL projection from that same filtered list so it can never disagree
+        // with the columns we later decode the row with.
+        let projected_columns = match &options.columns {
+            Some(names) => {
+                let mut matched = Vec::with_capacity(names.len());
+                for name in names {
+                    let column = self
+                        .columns
+                        .iter()
+                        .find(|c| &c.name == name)
+                        .ok_or_else(|| Error::UnknownColumn(name.clone()))?;
+                    matched.push(column.clone());
+                }
+                matched
+            }
+            None => self.columns.clone(),
+        };
+        let projection = if options.columns.is_some() {
+            projected_columns
+                .iter()
+                .map(|c| c.name.clone())
+                .collect::<Vec<_>>()
+                .join(", ")
+        } else {
+            "*".to_string()
+        };
+        for (col, _) in &options.order_by {
+            if !self.columns.iter().any(|c| &c.name == col) {
+                return Err(Error::UnknownColumn(col.clone()));
+            }
+        }
+
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let mut sql = format!("SELECT {} FROM {}", projection, self.name);
+        if let Some(cond) = condition {
+            let cond_sql = cond.build(&mut args, &mut idx);
+            sql.push_str(" WHERE ");
+            sql.push_str(&cond_sql);
+        }
+        if !options.order_by.is_empty() {
+            let order_sql = options
+                .order_by
+                .iter()
+                .map(|(col, order)| format!("{} {}", col, order.as_sql()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&order_sql);
+        }
+        if let Some(limit) = options.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = options.offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let rows = client.query(&sql, args).await?;
+        rows.iter()
+            .map(|row| row_to_map(row, &projected_columns))
+            .collect()
+    }
+
+    /// Convenience wrapper around `select` that pages through results and also
+    /// returns the total row count matching `condition` (via a separate `COUNT(*)`).
+    pub async fn select_page<C: Client>(
+        &self,
+        client: &C,
+        condition: Option<Condition>,
+        page: i64,
+        per_page: i64,
+    ) -> Result<(Vec<HashMap<String, Value>>, i64), Error> {
+        let offset = (page.max(1) - 1) * per_page;
+        let options = SelectOptions {
+            limit: Some(per_page),
+            offset: Some(offset),
+            ..Default::default()
+        };
+        let rows = self
+            .select(client, condition.clone(), Some(options))
+            .await?;
+
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let mut count_sql = format!("SELECT COUNT(*) AS count FROM {}", self.name);
+        if let Some(cond) = condition {
+            let cond_sql = cond.build(&mut args, &mut idx);
+            count_sql.push_str(" WHERE ");
+            count_sql.push_str(&cond_sql);
+        }
+        let count_rows = client.query(&count_sql, args).await?;
+        let total = match count_rows.first() {
+            Some(row) => match row.get_value("count", &DataType::Int)? {
+                Value::Int(n) => n,
+                _ => 0,
+            },
+            None => 0,
+        };
+
+        Ok((rows, total))
+    }
+
+    pub async fn update<C: Client>(
+        &self,
+        client: &C,
+        set: HashMap<String, Value>,
+        condition: Option<Condition>,
+    ) -> Result<Vec<HashMap<String, Value>>, Error> {
+        if set.is_empty() {
+            return Err(Error::EmptyUpdateSet);
+        }
+        for col in set.keys() {
+            if !self.columns.iter().any(|c| &c.name == col) {
+                return Err(Error::UnknownColumn(col.clone()));
+            }
+        }
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let mut assignments = Vec::new();
+        for (col, val) in &set {
+            match val {
+                Value::Null => assignments.push(format!("{} = NULL", col)),
+                _ => {
+                    idx += 1;
+                    assignments.push(format!("{} = {}", col, val.placeholder(idx)));
+                    args.push(val.clone());
+                }
+            }
+        }
+        let mut sql = format!("UPDATE {} SET {}", self.name, assignments.join(", "));
+        if let Some(cond) = condition {
+            let cond_sql = cond.build(&mut args, &mut idx);
+            sql.push_str(" WHERE ");
+            sql.push_str(&cond_sql);
+        }
+        sql.push_str(" RETURNING *");
+        let rows = client.query(&sql, args).await?;
+        rows.iter().map(|row| row_to_map(row, &self.columns)).collect()
+    }
+
+    pub async fn delete<C: Client>(
+        &self,
+        client: &C,
+        condition: Option<Condition>,
+    ) -> Result<Vec<HashMap<String, Value>>, Error> {
+        let mut args = Vec::new();
+        let mut idx = 0;
+        let mut sql = format!("DELETE FROM {}", self.name);
+        if let Some(cond) = condition {
+            let cond_sql = cond.build(&mut args, &mut idx);
+            sql.push_str(" WHERE ");
+            sql.push_str(&cond_sql);
+        }
+        sql.push_str(" RETURNING *");
+        let rows = client.query(&sql, args).await?;
+        rows.iter().map(|row| row_to_map(row, &self.columns)).collect()
+    }
+
+    /// Generates the DDL for a trigger function that publishes insert/update/delete
+    /// events on this table via `pg_notify`, for consumption by `Listener::subscribe`.
+    pub fn change_trigger_ddl(&self) -> String {
+        let channel = change_channel(&self.name);
+        let function_name = format!("{}_notify_change", self.name);
+        let trigger_name = format!("{}_notify_change_trigger", self.name);
+        format!(
+            "CREATE OR REPLACE FUNCTION {function_name}() RETURNS trigger AS $$\n\
+             BEGIN\n\
+             \tPERFORM pg_notify('{channel}', json_build_object('op', TG_OP, 'row', row_to_json(COALESCE(NEW, OLD)))::text);\n\
+             \tRETURN COALESCE(NEW, OLD);\n\
+             END;\n\
+             $$ LANGUAGE plpgsql;\n\
+             \n\
+             DROP TRIGGER IF EXISTS {trigger_name} ON {table};\n\
+             CREATE TRIGGER {trigger_name}\n\
+             AFTER INSERT OR UPDATE OR DELETE ON {table}\n\
+             FOR EACH ROW EXECUTE FUNCTION {function_name}();",
+            function_name = function_name,
+            channel = channel,
+            trigger_name = trigger_name,
+            table = self.name,
+        )
+    }
+
+    /// Installs the trigger returned by `change_trigger_ddl` via `client`.
+    pub async fn install_change_trigger<C: Client>(&self, client: &C) -> Result<(), Error> {
+        client.execute(&self.change_trigger_ddl(), Vec::new()).await?;
+        Ok(())
+    }
+
+    /// Generates the `CREATE TYPE ... AS ENUM` DDL for a native enum column
+    /// (see `EnumStorage::Native`).
+    pub fn create_enum_type_ddl(type_name: &str, variants: &[String]) -> String {
+        let values = variants
+            .iter()
+            .map(|v| format!("'{}'", v.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!("CREATE TYPE {} AS ENUM ({})", type_name, values)
+    }
+}
+
+fn row_to_map(row: &Row, columns: &[Column]) -> Result<HashMap<String, Value>, Error> {
+    let mut map = HashMap::new();
+    for col in columns {
+        map.insert(col.name.clone(), row.get_value(&col.name, &col.data_type)?);
+    }
+    Ok(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::Mutex;
+
+    /// A `Client` stub that records the SQL/params it's given instead of
+    /// talking to Postgres, so the SQL this module builds can be unit-tested
+    /// without a live database.
+    #[derive(Default)]
+    struct RecordingClient {
+        queries: Mutex<Vec<(String, Vec<Value>)>>,
+    }
+
+    impl RecordingClient {
+        fn last_sql(&self) -> String {
+            self.queries.lock().unwrap().last().unwrap().0.clone()
+        }
+    }
+
+    #[async_trait]
+    impl Client for RecordingClient {
+        async fn execute(&self, sql: &str, params: Vec<Value>) -> Result<u64, Error> {
+            self.queries.lock().unwrap().push((sql.to_string(), params));
+            Ok(0)
+        }
+
+        async fn query(&self, sql: &str, params: Vec<Value>) -> Result<Vec<Row>, Error> {
+            self.queries.lock().unwrap().push((sql.to_string(), params));
+            Ok(Vec::new())
+        }
+    }
+
+    fn items_table() -> Table {
+        Table::new(
+            "items",
+            vec![
+                Column::new("id", DataType::Int),
+                Column::new("name", DataType::Text),
+            ],
+        )
+    }
+
+    #[tokio::test]
+    async fn introspect_filters_both_queries_by_the_given_schema() {
+        let client = RecordingClient::default();
+        Table::introspect(&client, "public", "items").await.unwrap();
+        let queries = client.queries.lock().unwrap();
+        assert_eq!(queries.len(), 2);
+        assert!(queries[0].0.contains("tc.table_schema = $1"));
+        assert!(queries[0].0.contains("tc.table_name = $2"));
+        assert_eq!(
+            queries[0].1,
+            vec![
+                Value::Text("public".to_string()),
+                Value::Text("items".to_string())
+            ]
+        );
+        assert!(queries[1].0.contains("table_schema = $1"));
+        assert!(queries[1].0.contains("table_name = $2"));
+        assert_eq!(
+            queries[1].1,
+            vec![
+                Value::Text("public".to_string()),
+                Value::Text("items".to_string())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn update_with_empty_set_is_rejected_without_touching_the_client() {
+        let client = RecordingClient::default();
+        let err = items_table()
+            .update(&client, HashMap::new(), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::EmptyUpdateSet));
+        assert!(client.queries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_with_an_unknown_column_is_rejected_without_touching_the_client() {
+        let client = RecordingClient::default();
+        let mut set = HashMap::new();
+        set.insert("does_not_exist".to_string(), Value::Text("x".to_string()));
+        let err = items_table().update(&client, set, None).await.unwrap_err();
+        assert!(matches!(err, Error::UnknownColumn(col) if col == "does_not_exist"));
+        assert!(client.queries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_with_values_sets_the_given_columns() {
+        let client = RecordingClient::default();
+        let mut set = HashMap::new();
+        set.insert("name".to_string(), Value::Text("new".to_string()));
+        items_table().update(&client, set, None).await.unwrap();
+        let sql = client.last_sql();
+        assert!(sql.starts_with("UPDATE items SET name = $1"));
+        assert!(sql.ends_with("RETURNING *"));
+    }
+
+    #[tokio::test]
+    async fn update_with_a_native_enum_casts_the_placeholder_to_its_type_name() {
+        use crate::types::{EnumDescriptor, EnumStorage};
+
+        let table = Table::new(
+            "items",
+            vec![
+                Column::new("id", DataType::Int),
+                Column::new(
+                    "color",
+                    DataType::Enum(EnumDescriptor {
+                        variants: vec!["red".to_string(), "green".to_string()],
+                        storage: EnumStorage::Native("color".to_string()),
+                    }),
+                ),
+            ],
+        );
+        let client = RecordingClient::default();
+        let mut set = HashMap::new();
+        set.insert(
+            "color".to_string(),
+            Value::Enum(
+                "green".to_string(),
+                EnumDescriptor {
+                    variants: vec!["red".to_string(), "green".to_string()],
+                    storage: EnumStorage::Native("color".to_string()),
+                },
+            ),
+        );
+        table.update(&client, set, None).await.unwrap();
+        assert!(client.last_sql().contains("color = $1::color"));
+    }
+
+    #[tokio::test]
+    async fn select_with_no_options_projects_every_column() {
+        let client = RecordingClient::default();
+        items_table().select(&client, None, None).await.unwrap();
+        assert_eq!(client.last_sql(), "SELECT * FROM items");
+    }
+
+    #[tokio::test]
+    async fn select_applies_order_by_limit_and_offset() {
+        use crate::select::Order;
+
+        let client = RecordingClient::default();
+        let options = SelectOptions {
+            order_by: vec![("name".to_string(), Order::Desc)],
+            limit: Some(10),
+            offset: Some(5),
+            columns: None,
+        };
+        items_table()
+            .select(&client, None, Some(options))
+            .await
+            .unwrap();
+        let sql = client.last_sql();
+        assert!(sql.contains("ORDER BY name DESC"));
+        assert!(sql.contains("LIMIT 10"));
+        assert!(sql.contains("OFFSET 5"));
+    }
+
+    #[tokio::test]
+    async fn select_projects_only_the_requested_columns() {
+        let client = RecordingClient::default();
+        let options = SelectOptions {
+            columns: Some(vec!["name".to_string()]),
+            ..Default::default()
+        };
+        items_table()
+            .select(&client, None, Some(options))
+            .await
+            .unwrap();
+        assert_eq!(client.last_sql(), "SELECT name FROM items");
+    }
+
+    #[tokio::test]
+    async fn select_rejects_an_unknown_column_without_touching_the_client() {
+        let client = RecordingClient::default();
+        let options = SelectOptions {
+            columns: Some(vec!["does_not_exist".to_string()]),
+            ..Default::default()
+        };
+        let err = items_table()
+            .select(&client, None, Some(options))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::UnknownColumn(col) if col == "does_not_exist"));
+        assert!(client.queries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn select_rejects_an_unknown_order_by_column_without_touching_the_client() {
+        use crate::select::Order;
+
+        let client = RecordingClient::default();
+        let options = SelectOptions {
+            order_by: vec![("does_not_exist".to_string(), Order::Asc)],
+            ..Default::default()
+        };
+        let err = items_table()
+            .select(&client, None, Some(options))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::UnknownColumn(col) if col == "does_not_exist"));
+        assert!(client.queries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn select_page_limits_and_offsets_by_page_and_counts_separately() {
+        let client = RecordingClient::default();
+        let (rows, total) = items_table()
+            .select_page(&client, None, 3, 20)
+            .await
+            .unwrap();
+        assert!(rows.is_empty());
+        assert_eq!(total, 0);
+        let queries = client.queries.lock().unwrap();
+        assert!(queries[0].0.contains("LIMIT 20"));
+        assert!(queries[0].0.contains("OFFSET 40"));
+        assert_eq!(queries[1].0, "SELECT COUNT(*) AS count FROM items");
+    }
+}
@@ -0,0 +1,94 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use uuid::Uuid;
+
+#[derive(Clone, Debug)]
+pub enum DataType {
+    Int,
+    Text,
+    Bool,
+    Timestamp,
+    /// `timestamp with time zone` — unlike `Timestamp`, this carries an offset
+    /// and round-trips through sqlx as `DateTime<Utc>`, not `NaiveDateTime`.
+    Timestamptz,
+    Date,
+    Time,
+    Numeric,
+    Uuid,
+    Json,
+    Array(Box<DataType>),
+    /// A Postgres type with no `DataType` equivalent, keyed by its `udt_name`.
+    /// Introspection falls back to this instead of failing outright.
+    Unsupported(String),
+    Enum(EnumDescriptor),
+}
+
+/// Describes how a Rust-level enum's variants round-trip to Postgres.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumDescriptor {
+    pub variants: Vec<String>,
+    pub storage: EnumStorage,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EnumStorage {
+    /// Stored as an integer discriminant (the variant's position in `variants`).
+    Weak,
+    /// Stored as plain `TEXT`.
+    Strong,
+    /// Stored as a native `CREATE TYPE ... AS ENUM` type, addressed by name.
+    Native(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub primary_key: bool,
+}
+
+impl Column {
+    pub fn new(name: &str, data_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            nullable: true,
+            primary_key: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Text(String),
+    Bool(bool),
+    Timestamp(NaiveDateTime),
+    Timestamptz(DateTime<Utc>),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    Numeric(BigDecimal),
+    Uuid(Uuid),
+    Json(serde_json::Value),
+    Array(Vec<Value>),
+    /// A variant label plus the descriptor that says how to encode/decode it.
+    Enum(String, EnumDescriptor),
+    Null,
+}
+
+impl Value {
+    /// The placeholder text to splice into generated SQL for this value at
+    /// position `idx`. A `Native` enum needs an explicit `::type_name` cast:
+    /// Postgres's extended query protocol binds `$N` as TEXT and won't
+    /// implicitly coerce it to a named enum type the way a text literal would be.
+    pub(crate) fn placeholder(&self, idx: i32) -> String {
+        match self {
+            Value::Enum(_, EnumDescriptor {
+                storage: EnumStorage::Native(type_name),
+                ..
+            }) => format!("${}::{}", idx, type_name),
+            _ => format!("${}", idx),
+        }
+    }
+}
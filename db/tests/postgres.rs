@@ -1,4 +1,4 @@
-use db::{Column, DataType, Table, Value};
+use db::{Column, DataType, SqlxClient, Table, Value};
 use sqlx::postgres::PgPoolOptions;
 use std::collections::HashMap;
 use testcontainers::runners::AsyncRunner;
@@ -23,17 +23,13 @@ async fn insert_and_select() -> Result<(), Box<dyn std::error::Error>> {
         .execute(&pool)
         .await?;
 
+    let client = SqlxClient::new(pool);
+
     let table = Table::new(
         "items",
         vec![
-            Column {
-                name: "id".into(),
-                data_type: DataType::Int,
-            },
-            Column {
-                name: "name".into(),
-                data_type: DataType::Text,
-            },
+            Column::new("id", DataType::Int),
+            Column::new("name", DataType::Text),
         ],
     );
 
@@ -41,9 +37,9 @@ async fn insert_and_select() -> Result<(), Box<dyn std::error::Error>> {
     values.insert("id".into(), Value::Int(1));
     values.insert("name".into(), Value::Text("hello".into()));
 
-    table.insert(&pool, values).await?;
+    table.insert(&client, values).await?;
 
-    let rows = table.select(&pool, None).await?;
+    let rows = table.select(&client, None, None).await?;
     assert_eq!(rows.len(), 1);
     assert_eq!(rows[0].get("name"), Some(&Value::Text("hello".into())));
 